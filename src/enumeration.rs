@@ -0,0 +1,275 @@
+use crate::{Pauli, PauliOperator};
+use Pauli::{X, Y, Z};
+
+const NON_IDENTITY_PAULIS: [Pauli; 3] = [X, Y, Z];
+
+fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        0
+    } else {
+        let k = k.min(n - k);
+        (0..k).fold(1usize, |acc, i| acc * (n - i) / (i + 1))
+    }
+}
+
+fn first_combination(weight: usize) -> Vec<usize> {
+    (0..weight).collect()
+}
+
+/// Bumps a combination of `weight` positions among `0..length` to the
+/// next one in lexicographic order: finds the rightmost index that
+/// can be incremented, increments it, and resets the tail to
+/// consecutive values. Returns `None` once the last combination has
+/// been reached.
+fn next_combination(mut combination: Vec<usize>, length: usize) -> Option<Vec<usize>> {
+    let weight = combination.len();
+    let mut index = weight;
+    loop {
+        if index == 0 {
+            return None;
+        }
+        index -= 1;
+        if combination[index] < length - weight + index {
+            break;
+        }
+    }
+    combination[index] += 1;
+    for position in index + 1..weight {
+        combination[position] = combination[position - 1] + 1;
+    }
+    Some(combination)
+}
+
+/// Decodes `index` as a mixed-radix base-3 counter, one digit per
+/// position, mapping each digit to `X`, `Y` or `Z`.
+fn paulis_from_assignment(mut index: usize, weight: usize) -> Vec<Pauli> {
+    (0..weight)
+        .map(|_| {
+            let pauli = NON_IDENTITY_PAULIS[index % 3];
+            index /= 3;
+            pauli
+        })
+        .collect()
+}
+
+/// An iterator over every [`PauliOperator`] on `n` qubits with
+/// exactly `k` non-identity positions, produced by
+/// [`PauliOperator::all_of_weight`].
+///
+/// Position sets are enumerated in lexicographic combination order,
+/// and for each one every assignment of `{X, Y, Z}` to its `k`
+/// positions is emitted before moving on to the next combination.
+#[derive(Debug, Clone)]
+pub struct AllOfWeight {
+    length: usize,
+    weight: usize,
+    combination: Option<Vec<usize>>,
+    assignment: usize,
+    assignment_count: usize,
+    remaining: usize,
+}
+
+impl AllOfWeight {
+    pub(crate) fn new(length: usize, weight: usize) -> Self {
+        let assignment_count = 3usize.pow(weight as u32);
+        let combination = (weight <= length).then(|| first_combination(weight));
+        let remaining = binomial(length, weight) * assignment_count;
+        Self {
+            length,
+            weight,
+            combination,
+            assignment: 0,
+            assignment_count,
+            remaining,
+        }
+    }
+}
+
+impl Iterator for AllOfWeight {
+    type Item = PauliOperator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let positions = self.combination.clone()?;
+        if self.assignment == self.assignment_count {
+            self.combination = next_combination(positions, self.length);
+            self.assignment = 0;
+            return self.next();
+        }
+        let paulis = paulis_from_assignment(self.assignment, self.weight);
+        self.assignment += 1;
+        self.remaining -= 1;
+        Some(PauliOperator::new(self.length, positions, paulis))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for AllOfWeight {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+/// An iterator over the `2 ** m` products of an `m`-element generator
+/// list, produced by [`generated_subgroup`].
+///
+/// Subsets of generators are walked in Gray code order, as in
+/// [`StabilizerGroup::weight_enumerator`](crate::StabilizerGroup::weight_enumerator),
+/// so that each step toggles exactly one generator into a running
+/// product instead of recomputing it from scratch, keeping every step
+/// `O(weight)` rather than `O(m * weight)`.
+#[derive(Debug, Clone)]
+pub struct GeneratedSubgroup {
+    generators: Vec<PauliOperator>,
+    running: PauliOperator,
+    subset: u64,
+    total: u64,
+}
+
+impl GeneratedSubgroup {
+    pub(crate) fn new(generators: &[PauliOperator]) -> Self {
+        let length = generators.first().map_or(0, PauliOperator::len);
+        assert!(
+            generators.iter().all(|g| g.len() == length),
+            "all generators must have the same length"
+        );
+        assert!(
+            generators.len() < 64,
+            "generated_subgroup supports at most 63 generators, got {}",
+            generators.len()
+        );
+        Self {
+            running: PauliOperator::new(length, Vec::new(), Vec::new()),
+            total: 1u64 << generators.len(),
+            generators: generators.to_vec(),
+            subset: 0,
+        }
+    }
+}
+
+impl Iterator for GeneratedSubgroup {
+    type Item = PauliOperator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.subset == self.total {
+            return None;
+        }
+        if self.subset > 0 {
+            let toggled = self.subset.trailing_zeros() as usize;
+            self.running = self.running.multiply_with(&self.generators[toggled]).unwrap();
+        }
+        self.subset += 1;
+        Some(self.running.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for GeneratedSubgroup {
+    fn len(&self) -> usize {
+        (self.total - self.subset) as usize
+    }
+}
+
+/// Returns an iterator over the `2 ** m` products of `generators`,
+/// i.e. the Pauli group they generate.
+///
+/// # Panic
+///
+/// Panics if the generators don't all have the same length, or if
+/// there are 64 or more of them.
+///
+/// # Example
+///
+/// ```
+/// # use pauli::{generated_subgroup, PauliOperator};
+/// # use pauli::{X, Z};
+/// let generators = vec![
+///     PauliOperator::new(2, vec![0, 1], vec![Z, Z]),
+///     PauliOperator::new(2, vec![0, 1], vec![X, X]),
+/// ];
+/// let group: Vec<_> = generated_subgroup(&generators).collect();
+///
+/// assert_eq!(group.len(), 4);
+/// assert!(group.contains(&PauliOperator::new(2, vec![], vec![])));
+/// ```
+pub fn generated_subgroup(generators: &[PauliOperator]) -> GeneratedSubgroup {
+    GeneratedSubgroup::new(generators)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn counts_match_the_expected_formulas() {
+        assert_eq!(PauliOperator::all_of_weight(5, 2).len(), 10 * 9);
+        assert_eq!(PauliOperator::all_of_weight(5, 0).len(), 1);
+        assert_eq!(PauliOperator::all_of_weight(3, 4).len(), 0);
+    }
+
+    #[test]
+    fn size_hint_tracks_remaining_items() {
+        let mut iterator = PauliOperator::all_of_weight(4, 2);
+        let mut remaining = iterator.len();
+        while iterator.next().is_some() {
+            remaining -= 1;
+            assert_eq!(iterator.len(), remaining);
+        }
+    }
+
+    #[test]
+    fn every_operator_has_the_requested_weight_and_no_duplicates() {
+        let operators: BTreeSet<_> = PauliOperator::all_of_weight(4, 2).collect();
+        assert_eq!(operators.len(), PauliOperator::all_of_weight(4, 2).len());
+        for operator in &operators {
+            assert_eq!(operator.weight(), 2);
+        }
+    }
+
+    #[test]
+    fn weight_zero_yields_only_the_identity() {
+        let operators: Vec<_> = PauliOperator::all_of_weight(3, 0).collect();
+        assert_eq!(operators, vec![PauliOperator::new(3, vec![], vec![])]);
+    }
+
+    #[test]
+    fn generated_subgroup_has_two_to_the_m_elements() {
+        let generators = vec![
+            PauliOperator::new(3, vec![0, 1, 2], vec![Pauli::Z, Pauli::Z, Pauli::Z]),
+            PauliOperator::new(3, vec![0, 1], vec![Pauli::X, Pauli::X]),
+        ];
+        let group: Vec<_> = generated_subgroup(&generators).collect();
+        assert_eq!(group.len(), 4);
+        assert_eq!(generated_subgroup(&generators).len(), 4);
+    }
+
+    #[test]
+    fn generated_subgroup_matches_brute_force_products() {
+        let generators = vec![
+            PauliOperator::new(3, vec![0, 1, 2], vec![Pauli::Z, Pauli::Z, Pauli::Z]),
+            PauliOperator::new(3, vec![0, 1], vec![Pauli::X, Pauli::X]),
+            PauliOperator::new(3, vec![1, 2], vec![Pauli::Y, Pauli::Y]),
+        ];
+
+        let mut brute_force = BTreeSet::new();
+        for subset in 0..(1usize << generators.len()) {
+            let mut element = PauliOperator::new(3, vec![], vec![]);
+            for (bit, generator) in generators.iter().enumerate() {
+                if subset & (1 << bit) != 0 {
+                    element = element.multiply_with(generator).unwrap();
+                }
+            }
+            brute_force.insert(element);
+        }
+
+        let group: BTreeSet<_> = generated_subgroup(&generators).collect();
+        assert_eq!(group, brute_force);
+    }
+}
@@ -4,12 +4,28 @@
 //! Pauli operators `I`, `X`, `Y` and `Z` and
 //! of general multi-qubit Pauli operators such as `XYZ`.
 //!
-//! This library is built with error correction in mind
-//! thus the phases are ignored.
+//! Multi-qubit operators come in a few flavours depending on
+//! what you need: `PauliOperator` is a sparse representation
+//! for operators that are mostly identity, `DensePauliOperator`
+//! keeps track of a global `Phase`, and `SymplecticPauli` is a
+//! bit-packed representation built for fast commutation and
+//! multiplication on many qubits.
 
-mod base;
-pub use base::Pauli;
+mod pauli;
+pub use pauli::Pauli;
 pub use Pauli::{I, X, Y, Z};
 
+mod phase;
+pub use phase::Phase;
+
 mod operator;
 pub use operator::{PauliError, PauliOperator};
+
+mod operators;
+pub use operators::{DensePauliOperator, SymplecticPauli};
+
+mod stabilizer;
+pub use stabilizer::{Membership, StabilizerGroup};
+
+mod enumeration;
+pub use enumeration::{generated_subgroup, AllOfWeight, GeneratedSubgroup};
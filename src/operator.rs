@@ -1,10 +1,12 @@
+use crate::DensePauliOperator;
 use crate::Pauli;
+use crate::Phase;
 use sprs::vec::{NnzEither, SparseIterTools, VectorIterator};
 use sprs::CsVec;
 use std::error::Error;
 use std::fmt;
 use std::ops::Mul;
-use Pauli::{X, Z};
+use Pauli::{I, X, Y, Z};
 
 /// A Pauli operator optimized for sparse operations.
 ///
@@ -300,6 +302,50 @@ impl PauliOperator {
         }
     }
 
+    /// Returns the product of two operators together with the phase
+    /// accumulated from anticommuting single-qubit factors (e.g.
+    /// `X * Z = -iY`), or an Error if they have different lengths.
+    ///
+    /// Unlike [`multiply_with`](Self::multiply_with), which is only
+    /// correct up to sign, this tracks the exact phase, at the cost
+    /// of no longer being a simple element-wise product.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pauli::PauliOperator;
+    /// # use pauli::{X, Y, Z};
+    /// # use pauli::Phase;
+    /// let op1 = PauliOperator::new(2, vec![0], vec![X]);
+    /// let op2 = PauliOperator::new(2, vec![0], vec![Z]);
+    ///
+    /// let (product, phase) = op1.multiply_with_phase(&op2).unwrap();
+    ///
+    /// assert_eq!(product, PauliOperator::new(2, vec![0], vec![Y]));
+    /// assert_eq!(phase, Phase::minus_i());
+    /// ```
+    pub fn multiply_with_phase(&self, other: &Self) -> Result<(Self, Phase), PauliError> {
+        if self.len() != other.len() {
+            return Err(PauliError::IncompatibleLength(self.len(), other.len()));
+        }
+        let mut phase = Phase::one();
+        let (positions, paulis) = self
+            .iter()
+            .nnz_or_zip(other.iter())
+            .map(|values| match values {
+                NnzEither::Left((position, &pauli)) => (position, pauli),
+                NnzEither::Right((position, &pauli)) => (position, pauli),
+                NnzEither::Both((position, &p0, &p1)) => {
+                    let (local_phase, product) = p0.multiply_with_phase(p1);
+                    phase *= local_phase;
+                    (position, product)
+                }
+            })
+            .filter(|(_, pauli)| pauli.is_non_trivial())
+            .unzip();
+        Ok((PauliOperator::new(self.len(), positions, paulis), phase))
+    }
+
     /// Converts a PauliOperator to a Vec of its non trivial positions
     /// consumming the operator.
     ///
@@ -348,6 +394,20 @@ impl PauliOperator {
     pub fn into_raw(self) -> (Vec<usize>, Vec<Pauli>) {
         self.paulis.into_raw_storage()
     }
+
+    /// Returns an iterator over every operator on `n` qubits with
+    /// exactly `k` non-identity positions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pauli::PauliOperator;
+    /// let operators: Vec<_> = PauliOperator::all_of_weight(2, 1).collect();
+    /// assert_eq!(operators.len(), 6); // 2 positions * 3 Paulis
+    /// ```
+    pub fn all_of_weight(n: usize, k: usize) -> crate::enumeration::AllOfWeight {
+        crate::enumeration::AllOfWeight::new(n, k)
+    }
 }
 
 impl<'a> Mul<&'a PauliOperator> for &'a PauliOperator {
@@ -358,6 +418,47 @@ impl<'a> Mul<&'a PauliOperator> for &'a PauliOperator {
     }
 }
 
+/// Compares a sparse operator against a dense one by their Paulis
+/// only, ignoring the dense operator's phase. The shorter operator is
+/// padded with `I` rather than making the comparison panic.
+impl PartialEq<DensePauliOperator> for PauliOperator {
+    fn eq(&self, other: &DensePauliOperator) -> bool {
+        let other_paulis: Vec<Pauli> = other.paulis().collect();
+        let length = self.len().max(other_paulis.len());
+        (0..length).all(|position| {
+            self.get(position).unwrap_or(Pauli::I)
+                == other_paulis.get(position).copied().unwrap_or(Pauli::I)
+        })
+    }
+}
+
+/// Compares operators lexicographically over their dense view,
+/// position by position under `I < X < Y < Z`. Operators whose
+/// common prefix is equal but whose lengths differ are ordered by
+/// length, the shorter one first, so that `cmp` never reports
+/// `Equal` for operators of different lengths, agreeing with the
+/// derived `Eq`. This lets operators live in sorted collections
+/// such as `BTreeSet` without hashing.
+impl PartialOrd for PauliOperator {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PauliOperator {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let common_length = self.len().min(other.len());
+        (0..common_length)
+            .map(|position| {
+                self.get(position)
+                    .unwrap_or(I)
+                    .cmp(&other.get(position).unwrap_or(I))
+            })
+            .find(|ordering| *ordering != std::cmp::Ordering::Equal)
+            .unwrap_or_else(|| self.len().cmp(&other.len()))
+    }
+}
+
 impl fmt::Display for PauliOperator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "[")?;
@@ -375,6 +476,7 @@ impl fmt::Display for PauliOperator {
 pub enum PauliError {
     IncompatibleLength(usize, usize),
     OutOfBound(usize, usize),
+    InvalidPauliSymbol(char),
 }
 
 impl fmt::Display for PauliError {
@@ -386,16 +488,86 @@ impl fmt::Display for PauliError {
             Self::OutOfBound(pos, len) => {
                 write!(f, "position {} is out of bound for length {}", pos, len)
             }
+            Self::InvalidPauliSymbol(symbol) => {
+                write!(f, "'{}' is not a valid Pauli symbol, expected I, X, Y or Z", symbol)
+            }
         }
     }
 }
 
+/// Parses the dense form of an operator, e.g. `"XIYIZ"`, into one
+/// `Pauli` per character.
+fn parse_dense_paulis(string: &str) -> Result<Vec<Pauli>, char> {
+    string
+        .chars()
+        .map(|symbol| match symbol {
+            'I' => Ok(I),
+            'X' => Ok(X),
+            'Y' => Ok(Y),
+            'Z' => Ok(Z),
+            other => Err(other),
+        })
+        .collect()
+}
+
+/// Parses the dense form of an operator such as `"XIYIZ"`.
+///
+/// # Example
+///
+/// ```
+/// # use pauli::PauliOperator;
+/// # use pauli::{X, Y, Z};
+/// let operator: PauliOperator = "XIYIZ".parse().unwrap();
+/// assert_eq!(operator, PauliOperator::new(5, vec![0, 2, 4], vec![X, Y, Z]));
+/// ```
+impl std::str::FromStr for PauliOperator {
+    type Err = PauliError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let paulis = parse_dense_paulis(string).map_err(PauliError::InvalidPauliSymbol)?;
+        let length = paulis.len();
+        let (positions, paulis) = paulis
+            .into_iter()
+            .enumerate()
+            .filter(|(_, pauli)| pauli.is_non_trivial())
+            .unzip();
+        Ok(PauliOperator::new(length, positions, paulis))
+    }
+}
+
+/// Compares an operator against the dense form of an operator, e.g.
+/// `operator == "XIYIZ"`. The shorter side is padded with `I`.
+impl PartialEq<&str> for PauliOperator {
+    fn eq(&self, other: &&str) -> bool {
+        match parse_dense_paulis(other) {
+            Ok(paulis) => self == paulis.as_slice(),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Compares an operator against a dense sequence of Paulis. The
+/// shorter side is padded with `I`.
+impl PartialEq<[Pauli]> for PauliOperator {
+    fn eq(&self, other: &[Pauli]) -> bool {
+        let length = self.len().max(other.len());
+        (0..length).all(|position| {
+            self.get(position).unwrap_or(I) == other.get(position).copied().unwrap_or(I)
+        })
+    }
+}
+
+impl PartialEq<Vec<Pauli>> for PauliOperator {
+    fn eq(&self, other: &Vec<Pauli>) -> bool {
+        self == other.as_slice()
+    }
+}
+
 impl Error for PauliError {}
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use Pauli::{X, Z};
 
     #[test]
     fn commutes_with_different_lengths() {
@@ -410,4 +582,103 @@ mod test {
         let short_operator = PauliOperator::new(4, vec![0, 1, 2], vec![Z, Z, Z]);
         assert!(long_operator.anticommutes_with(&short_operator));
     }
+
+    #[test]
+    fn parses_dense_string() {
+        let operator: PauliOperator = "XIYIZ".parse().unwrap();
+        assert_eq!(operator, PauliOperator::new(5, vec![0, 2, 4], vec![X, Y, Z]));
+    }
+
+    #[test]
+    fn rejects_invalid_symbol() {
+        let result: Result<PauliOperator, _> = "XIA".parse();
+        assert_eq!(result, Err(PauliError::InvalidPauliSymbol('A')));
+    }
+
+    #[test]
+    fn equality_with_dense_string_pads_shorter_side_with_identity() {
+        let operator = PauliOperator::new(3, vec![0, 2], vec![X, Z]);
+        assert_eq!(operator, "XIZ");
+        assert_eq!(operator, "XIZI");
+    }
+
+    #[test]
+    fn equality_with_raw_slice() {
+        let operator = PauliOperator::new(3, vec![0, 2], vec![X, Z]);
+        assert_eq!(operator, vec![X, I, Z]);
+        assert_eq!(operator, [X, I, Z, I][..]);
+    }
+
+    #[test]
+    fn ordering_is_lexicographic_over_the_dense_view() {
+        let ix: PauliOperator = "IX".parse().unwrap();
+        let xi: PauliOperator = "XI".parse().unwrap();
+        let xy: PauliOperator = "XY".parse().unwrap();
+        assert!(ix < xi);
+        assert!(xi < xy);
+    }
+
+    #[test]
+    fn shorter_operator_with_equal_prefix_sorts_before_the_longer_one() {
+        let x: PauliOperator = "X".parse().unwrap();
+        let xi: PauliOperator = "XI".parse().unwrap();
+        let xy: PauliOperator = "XY".parse().unwrap();
+        assert!(x < xi);
+        assert_ne!(x.cmp(&xi), std::cmp::Ordering::Equal);
+        assert!(xi < xy);
+    }
+
+    #[test]
+    fn cmp_agrees_with_eq_across_lengths_in_btree_and_hash_sets() {
+        use std::collections::{BTreeSet, HashSet};
+        let x: PauliOperator = "X".parse().unwrap();
+        let xi: PauliOperator = "XI".parse().unwrap();
+        assert_ne!(x, xi);
+
+        let btree: BTreeSet<PauliOperator> = vec![x.clone(), xi.clone()].into_iter().collect();
+        assert_eq!(btree.len(), 2);
+
+        let hash: HashSet<PauliOperator> = vec![x, xi].into_iter().collect();
+        assert_eq!(hash.len(), 2);
+    }
+
+    #[test]
+    fn multiply_with_phase_tracks_anticommuting_factors() {
+        let op1 = PauliOperator::new(2, vec![0], vec![X]);
+        let op2 = PauliOperator::new(2, vec![0], vec![Z]);
+        let (product, phase) = op1.multiply_with_phase(&op2).unwrap();
+        assert_eq!(product, PauliOperator::new(2, vec![0], vec![Y]));
+        assert_eq!(phase, Phase::minus_i());
+    }
+
+    #[test]
+    fn multiply_with_phase_agrees_with_multiply_with_up_to_sign() {
+        let op1 = PauliOperator::new(5, vec![1, 2, 3], vec![X, Y, Z]);
+        let op2 = PauliOperator::new(5, vec![2, 3, 4], vec![Y, X, Z]);
+        let (product, _) = op1.multiply_with_phase(&op2).unwrap();
+        assert_eq!(product, op1.multiply_with(&op2).unwrap());
+    }
+
+    #[test]
+    fn multiply_with_phase_rejects_incompatible_lengths() {
+        let op1 = PauliOperator::new(2, vec![], vec![]);
+        let op2 = PauliOperator::new(3, vec![], vec![]);
+        assert_eq!(
+            op1.multiply_with_phase(&op2),
+            Err(PauliError::IncompatibleLength(2, 3))
+        );
+    }
+
+    #[test]
+    fn can_be_collected_into_a_btree_set() {
+        use std::collections::BTreeSet;
+        let set: BTreeSet<PauliOperator> = vec![
+            "XY".parse().unwrap(),
+            "IX".parse().unwrap(),
+            "XY".parse().unwrap(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+    }
 }
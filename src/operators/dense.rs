@@ -1,7 +1,14 @@
 use crate::Pauli;
+use crate::PauliOperator;
 use crate::Phase;
+use crate::SymplecticPauli;
 use itertools::Itertools;
 
+/// Above this length, commutation checks are routed through
+/// `SymplecticPauli` so they run in `O(n / 64)` word operations
+/// instead of `O(n)` per-qubit comparisons.
+const SYMPLECTIC_THRESHOLD: usize = 64;
+
 /// A dense Pauli operator is a global phase
 /// and a list of single qubit Paulis.
 ///
@@ -39,7 +46,7 @@ use itertools::Itertools;
 /// let product = &operator * &other_operator;
 /// assert_eq!(
 ///     product,
-///     DensePauliOperator::with_phase_and_paulis(Phase::one(), vec![I, Z, Y])
+///     DensePauliOperator::with_phase_and_paulis(Phase::i(), vec![I, Z, Y])
 /// );
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
@@ -48,6 +55,12 @@ pub struct DensePauliOperator {
     phase: Phase,
 }
 
+impl Default for DensePauliOperator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl DensePauliOperator {
     /// Creates a new empty operator.
     pub fn new() -> Self {
@@ -131,7 +144,7 @@ impl DensePauliOperator {
     pub fn non_trivial_paulis(&self) -> impl Iterator<Item = (usize, Pauli)> + '_ {
         self.paulis().enumerate().filter_map(|(position, pauli)| {
             if pauli.is_non_trivial() {
-                Some((position, pauli.clone()))
+                Some((position, pauli))
             } else {
                 None
             }
@@ -161,12 +174,16 @@ impl DensePauliOperator {
     /// Panics if operator have different lengths.
     pub fn commutes_with(&self, other: &Self) -> bool {
         assert_same_length(self, other);
-        self.paulis()
-            .zip(other.paulis())
-            .filter(|(p, q)| p.anticommutes_with(*q))
-            .count()
-            % 2
-            == 0
+        if self.len() > SYMPLECTIC_THRESHOLD {
+            SymplecticPauli::from(self).commutes_with(&SymplecticPauli::from(other))
+        } else {
+            self.paulis()
+                .zip(other.paulis())
+                .filter(|(p, q)| p.anticommutes_with(*q))
+                .count()
+                % 2
+                == 0
+        }
     }
 
     /// Checks if two operators anticommute.
@@ -190,12 +207,16 @@ impl DensePauliOperator {
     /// Panics if operator have different lengths.
     pub fn anticommutes_with(&self, other: &Self) -> bool {
         assert_same_length(self, other);
-        self.paulis()
-            .zip(other.paulis())
-            .filter(|(p, q)| p.anticommutes_with(*q))
-            .count()
-            % 2
-            == 1
+        if self.len() > SYMPLECTIC_THRESHOLD {
+            SymplecticPauli::from(self).anticommutes_with(&SymplecticPauli::from(other))
+        } else {
+            self.paulis()
+                .zip(other.paulis())
+                .filter(|(p, q)| p.anticommutes_with(*q))
+                .count()
+                % 2
+                == 1
+        }
     }
 }
 
@@ -274,6 +295,62 @@ fn assert_same_length(first: &DensePauliOperator, second: &DensePauliOperator) {
     }
 }
 
+impl From<&PauliOperator> for DensePauliOperator {
+    fn from(operator: &PauliOperator) -> Self {
+        let paulis = (0..operator.len())
+            .map(|position| operator.get(position).unwrap())
+            .collect();
+        DensePauliOperator::with_paulis(paulis)
+    }
+}
+
+impl std::ops::Mul<&PauliOperator> for &DensePauliOperator {
+    type Output = DensePauliOperator;
+
+    fn mul(self, other: &PauliOperator) -> DensePauliOperator {
+        self * &DensePauliOperator::from(other)
+    }
+}
+
+impl std::ops::Mul<&DensePauliOperator> for &PauliOperator {
+    type Output = DensePauliOperator;
+
+    fn mul(self, other: &DensePauliOperator) -> DensePauliOperator {
+        &DensePauliOperator::from(self) * other
+    }
+}
+
+/// Compares a dense operator against a sparse one by their Paulis
+/// only, ignoring the dense operator's phase. The shorter operator is
+/// padded with `I` rather than making the comparison panic.
+impl PartialEq<PauliOperator> for DensePauliOperator {
+    fn eq(&self, other: &PauliOperator) -> bool {
+        let length = self.len().max(other.len());
+        (0..length).all(|position| {
+            self.paulis.get(position).copied().unwrap_or(Pauli::I)
+                == other.get(position).unwrap_or(Pauli::I)
+        })
+    }
+}
+
+/// Compares a dense operator against a dense sequence of Paulis,
+/// ignoring phase. The shorter side is padded with `I`.
+impl PartialEq<[Pauli]> for DensePauliOperator {
+    fn eq(&self, other: &[Pauli]) -> bool {
+        let length = self.len().max(other.len());
+        (0..length).all(|position| {
+            self.paulis.get(position).copied().unwrap_or(Pauli::I)
+                == other.get(position).copied().unwrap_or(Pauli::I)
+        })
+    }
+}
+
+impl PartialEq<Vec<Pauli>> for DensePauliOperator {
+    fn eq(&self, other: &Vec<Pauli>) -> bool {
+        self == other.as_slice()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -308,4 +385,55 @@ mod test {
         assert_eq!(&operator * pauli, product);
         assert_eq!(pauli * &operator, product);
     }
+
+    #[test]
+    fn equality_with_sparse_operator() {
+        let dense = DensePauliOperator::with_paulis(vec![X, I, Y, I]);
+        let sparse = PauliOperator::new(4, vec![0, 2], vec![X, Y]);
+        assert_eq!(dense, sparse);
+        assert_eq!(sparse, dense);
+    }
+
+    #[test]
+    fn equality_with_raw_slice_pads_shorter_side_with_identity() {
+        let dense = DensePauliOperator::with_paulis(vec![X, Y, I]);
+        assert_eq!(dense, vec![X, Y]);
+        assert_eq!(dense, [X, Y, I, I][..]);
+    }
+
+    #[test]
+    fn commutation_above_symplectic_threshold_matches_naive_per_qubit_definition() {
+        // 200 qubits spans multiple 64-bit words, including word
+        // boundaries at positions 64 and 128.
+        let length = 200;
+        let mut first = vec![I; length];
+        let mut second = vec![I; length];
+        for position in [0, 1, 63, 64, 65, 127, 128, 129, 199] {
+            first[position] = X;
+            second[position] = Z;
+        }
+        let first = DensePauliOperator::with_paulis(first);
+        let second = DensePauliOperator::with_paulis(second);
+        assert!(first.len() > SYMPLECTIC_THRESHOLD);
+
+        let naive_anticommutes = first
+            .paulis()
+            .zip(second.paulis())
+            .filter(|(p, q)| p.anticommutes_with(*q))
+            .count()
+            % 2
+            == 1;
+
+        assert_eq!(first.commutes_with(&second), !naive_anticommutes);
+        assert_eq!(first.anticommutes_with(&second), naive_anticommutes);
+    }
+
+    #[test]
+    fn multiplication_with_sparse_operator() {
+        let dense = DensePauliOperator::with_phase_and_paulis(Phase::i(), vec![X, Y, Z]);
+        let sparse = PauliOperator::new(3, vec![1, 2], vec![Z, X]);
+        let product = DensePauliOperator::with_phase_and_paulis(Phase::minus_i(), vec![X, X, Y]);
+        assert_eq!(&dense * &sparse, product);
+        assert_eq!(&sparse * &dense, product);
+    }
 }
@@ -0,0 +1,5 @@
+mod dense;
+pub use dense::DensePauliOperator;
+
+mod symplectic;
+pub use symplectic::SymplecticPauli;
@@ -0,0 +1,318 @@
+use crate::{DensePauliOperator, Pauli, PauliError, Phase};
+use Pauli::{I, X, Y, Z};
+
+const BITS: usize = 64;
+
+/// A bit-packed symplectic (GF(2)) representation of a Pauli operator.
+///
+/// Each qubit is encoded as a pair of bits `(x, z)` packed into
+/// parallel `u64` words, with `I = (0, 0)`, `X = (1, 0)`, `Z = (0, 1)`
+/// and `Y = (1, 1)`. Commutation and multiplication then run in
+/// `O(n / 64)` word operations instead of matching qubits one by one,
+/// which matters once `n` grows into the hundreds or thousands.
+///
+/// # Example
+///
+/// ```
+/// # use pauli::{DensePauliOperator, SymplecticPauli};
+/// # use pauli::{X, Y, Z};
+/// let operator = DensePauliOperator::with_paulis(vec![X, Y, Z]);
+/// let symplectic = SymplecticPauli::from(&operator);
+///
+/// assert_eq!(DensePauliOperator::from(&symplectic), operator);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymplecticPauli {
+    length: usize,
+    x: Vec<u64>,
+    z: Vec<u64>,
+    phase: Phase,
+}
+
+impl SymplecticPauli {
+    /// Creates the identity operator on `length` qubits.
+    pub fn identity(length: usize) -> Self {
+        let words = Self::word_count(length);
+        Self {
+            length,
+            x: vec![0; words],
+            z: vec![0; words],
+            phase: Phase::one(),
+        }
+    }
+
+    fn word_count(length: usize) -> usize {
+        length.div_ceil(BITS)
+    }
+
+    /// Returns the number of qubits the operator acts on.
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if the operator acts on no qubit.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the phase of the operator.
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    /// Returns true if the operator is the identity, ignoring phase.
+    pub fn is_identity(&self) -> bool {
+        self.x.iter().all(|word| *word == 0) && self.z.iter().all(|word| *word == 0)
+    }
+
+    /// Reads a column of the `2 * length`-wide symplectic `(x | z)`
+    /// matrix: columns `0..length` are the `x` bits and columns
+    /// `length..2 * length` are the `z` bits.
+    pub(crate) fn column(&self, column: usize) -> bool {
+        if column < self.length {
+            Self::bit(&self.x, column)
+        } else {
+            Self::bit(&self.z, column - self.length)
+        }
+    }
+
+    /// Writes a column of the `2 * length`-wide symplectic `(x | z)`
+    /// matrix. See [`Self::column`].
+    pub(crate) fn set_column(&mut self, column: usize, value: bool) {
+        if column < self.length {
+            Self::set_bit(&mut self.x, column, value);
+        } else {
+            Self::set_bit(&mut self.z, column - self.length, value);
+        }
+    }
+
+    /// XORs this operator's `(x, z)` bits into `running` and returns
+    /// the resulting change in Hamming weight (the count of
+    /// non-identity positions).
+    ///
+    /// Only the words this operator actually touches are visited,
+    /// which is what makes toggling one generator at a time, as Gray
+    /// code subset enumeration does, cheap regardless of how many
+    /// other generators there are.
+    ///
+    /// # Panic
+    ///
+    /// Panics if `running` doesn't have the same length as `self`.
+    pub(crate) fn toggle_weight(&self, running: &mut SymplecticPauli) -> i64 {
+        assert_same_length(self, running);
+        let mut delta = 0i64;
+        for word in 0..self.x.len() {
+            if self.x[word] == 0 && self.z[word] == 0 {
+                continue;
+            }
+            let before = (running.x[word] | running.z[word]).count_ones();
+            running.x[word] ^= self.x[word];
+            running.z[word] ^= self.z[word];
+            let after = (running.x[word] | running.z[word]).count_ones();
+            delta += after as i64 - before as i64;
+        }
+        delta
+    }
+
+    /// Returns the operator with its `x` and `z` parts swapped, i.e.
+    /// every `X` becomes a `Z` and vice-versa, leaving `Y` and `I`
+    /// unchanged. This is the matrix of the symplectic form `Ω`.
+    pub(crate) fn swapped(&self) -> Self {
+        Self {
+            length: self.length,
+            x: self.z.clone(),
+            z: self.x.clone(),
+            phase: self.phase,
+        }
+    }
+
+    /// Returns the Pauli acting on the given position
+    /// or None if the position is out of bound.
+    pub fn get(&self, position: usize) -> Option<Pauli> {
+        if position >= self.length {
+            None
+        } else {
+            Some(pauli_from_bits(
+                Self::bit(&self.x, position),
+                Self::bit(&self.z, position),
+            ))
+        }
+    }
+
+    fn bit(words: &[u64], position: usize) -> bool {
+        (words[position / BITS] >> (position % BITS)) & 1 == 1
+    }
+
+    fn set_bit(words: &mut [u64], position: usize, value: bool) {
+        let mask = 1u64 << (position % BITS);
+        if value {
+            words[position / BITS] |= mask;
+        } else {
+            words[position / BITS] &= !mask;
+        }
+    }
+
+    /// Checks if two operators commute.
+    ///
+    /// This is the symplectic inner product: the parity of the
+    /// number of qubits where the `X` part of one operand overlaps
+    /// the `Z` part of the other (in either direction).
+    ///
+    /// # Panic
+    ///
+    /// Panics if the operators have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pauli::{DensePauliOperator, SymplecticPauli};
+    /// # use pauli::{I, X, Y, Z};
+    /// let op1 = SymplecticPauli::from(&DensePauliOperator::with_paulis(vec![X, Y, Z]));
+    /// let op2 = SymplecticPauli::from(&DensePauliOperator::with_paulis(vec![Y, Y, Y]));
+    /// let op3 = SymplecticPauli::from(&DensePauliOperator::with_paulis(vec![I, X, I]));
+    ///
+    /// assert!(op1.commutes_with(&op2));
+    /// assert!(!op1.commutes_with(&op3));
+    /// ```
+    pub fn commutes_with(&self, other: &Self) -> bool {
+        assert_same_length(self, other);
+        let parity: u32 = self
+            .x
+            .iter()
+            .zip(&other.z)
+            .chain(self.z.iter().zip(&other.x))
+            .map(|(a, b)| (a & b).count_ones())
+            .sum();
+        parity.is_multiple_of(2)
+    }
+
+    /// Checks if two operators anticommute.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the operators have different lengths.
+    pub fn anticommutes_with(&self, other: &Self) -> bool {
+        !self.commutes_with(other)
+    }
+
+    /// Returns the product of the two operators, phase included,
+    /// or an error if they have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pauli::{DensePauliOperator, SymplecticPauli};
+    /// # use pauli::{I, X, Y, Z};
+    /// let op1 = SymplecticPauli::from(&DensePauliOperator::with_paulis(vec![X, I]));
+    /// let op2 = SymplecticPauli::from(&DensePauliOperator::with_paulis(vec![Z, I]));
+    /// let product = op1.multiply_with(&op2).unwrap();
+    ///
+    /// assert_eq!(DensePauliOperator::from(&product).phase(), pauli::Phase::minus_i());
+    /// ```
+    pub fn multiply_with(&self, other: &Self) -> Result<Self, PauliError> {
+        if self.length != other.length {
+            return Err(PauliError::IncompatibleLength(self.length, other.length));
+        }
+        let mut x = vec![0u64; self.x.len()];
+        let mut z = vec![0u64; self.z.len()];
+        let mut exponent = 0u32;
+        for word in 0..self.x.len() {
+            let (x1, z1, x2, z2) = (self.x[word], self.z[word], other.x[word], other.z[word]);
+            x[word] = x1 ^ x2;
+            z[word] = z1 ^ z2;
+            // XY, YZ and ZX each contribute a factor i; YX, ZY and XZ a factor -i.
+            let (x_only, y, z_only) = (x1 & !z1, x1 & z1, z1 & !x1);
+            let plus = (x_only & x2 & z2) | (y & z2 & !x2) | (z_only & x2 & !z2);
+            let minus = (x_only & z2 & !x2) | (y & x2 & !z2) | (z_only & x2 & z2);
+            exponent = (exponent + plus.count_ones() + 3 * minus.count_ones()) % 4;
+        }
+        let mut phase = self.phase * other.phase;
+        (0..exponent).for_each(|_| phase *= Phase::i());
+        Ok(Self {
+            length: self.length,
+            x,
+            z,
+            phase,
+        })
+    }
+}
+
+fn pauli_from_bits(x: bool, z: bool) -> Pauli {
+    match (x, z) {
+        (false, false) => I,
+        (true, false) => X,
+        (false, true) => Z,
+        (true, true) => Y,
+    }
+}
+
+fn assert_same_length(first: &SymplecticPauli, second: &SymplecticPauli) {
+    if first.length != second.length {
+        panic!(
+            "operators have different length: {} and {}",
+            first.length, second.length
+        );
+    }
+}
+
+impl From<&DensePauliOperator> for SymplecticPauli {
+    fn from(operator: &DensePauliOperator) -> Self {
+        let mut symplectic = SymplecticPauli::identity(operator.len());
+        for (position, pauli) in operator.non_trivial_paulis() {
+            let (x, z) = match pauli {
+                I => (false, false),
+                X => (true, false),
+                Z => (false, true),
+                Y => (true, true),
+            };
+            SymplecticPauli::set_bit(&mut symplectic.x, position, x);
+            SymplecticPauli::set_bit(&mut symplectic.z, position, z);
+        }
+        symplectic.phase = operator.phase();
+        symplectic
+    }
+}
+
+impl From<&SymplecticPauli> for DensePauliOperator {
+    fn from(operator: &SymplecticPauli) -> Self {
+        let paulis = (0..operator.length)
+            .map(|position| operator.get(position).unwrap())
+            .collect();
+        DensePauliOperator::with_phase_and_paulis(operator.phase, paulis)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn roundtrip_through_dense() {
+        let operator = DensePauliOperator::with_paulis(vec![X, I, Y, I, Z, I, X, X]);
+        let symplectic = SymplecticPauli::from(&operator);
+        assert_eq!(DensePauliOperator::from(&symplectic), operator);
+    }
+
+    #[test]
+    fn commutation_matches_per_qubit_definition() {
+        let op1 = SymplecticPauli::from(&DensePauliOperator::with_paulis(vec![X, Y, Z]));
+        let op2 = SymplecticPauli::from(&DensePauliOperator::with_paulis(vec![Y, Y, Y]));
+        let op3 = SymplecticPauli::from(&DensePauliOperator::with_paulis(vec![I, X, I]));
+
+        assert!(op1.commutes_with(&op2));
+        assert!(!op1.commutes_with(&op3));
+        assert!(!op2.commutes_with(&op3));
+    }
+
+    #[test]
+    fn multiplication_matches_dense_multiplication() {
+        let first = DensePauliOperator::with_paulis(vec![I, X, Y, Z]);
+        let second = DensePauliOperator::with_paulis(vec![X, Z, X, Z]);
+        let product = &first * &second;
+
+        let symplectic_product =
+            SymplecticPauli::from(&first).multiply_with(&SymplecticPauli::from(&second)).unwrap();
+
+        assert_eq!(DensePauliOperator::from(&symplectic_product), product);
+    }
+}
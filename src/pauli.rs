@@ -7,6 +7,10 @@ use Pauli::{I, X, Y, Z};
 /// These operators form a multiplicative group
 /// and follow the usual commutation and anti-commutation relations.
 ///
+/// Paulis are also totally ordered, `I < X < Y < Z`, matching their
+/// declaration order. This gives [`PauliOperator`](crate::PauliOperator)
+/// a lexicographic ordering over its dense view.
+///
 /// # Example
 ///
 /// ```
@@ -18,7 +22,7 @@ use Pauli::{I, X, Y, Z};
 /// assert!(X.commutes_with(I));
 /// assert!(Y.anticommutes_with(Z));
 /// ```
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 pub enum Pauli {
     I,
     X,
@@ -173,6 +177,13 @@ mod test {
         assert_eq!(Z * Z, I);
     }
 
+    #[test]
+    fn ordering() {
+        assert!(I < X);
+        assert!(X < Y);
+        assert!(Y < Z);
+    }
+
     #[test]
     fn multiplication_with_phase() {
         assert_eq!(I.multiply_with_phase(I), (Phase::one(), I));
@@ -0,0 +1,318 @@
+use crate::{DensePauliOperator, Phase, SymplecticPauli};
+
+/// The result of testing whether an operator belongs to a
+/// [`StabilizerGroup`].
+///
+/// Membership is decided up to sign: an operator whose Pauli part is
+/// generated by the group but whose phase disagrees with the group's
+/// convention is reported as [`Membership::PhaseMismatch`] rather
+/// than silently treated as absent or present.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Membership {
+    /// The operator is not in the group, even up to phase.
+    NotInGroup,
+    /// The operator is exactly a product of the generators.
+    Member,
+    /// The operator's Pauli part is generated by the group, but the
+    /// accumulated phase of that product differs from the operator's
+    /// own phase by the given factor.
+    PhaseMismatch(Phase),
+}
+
+/// A group of commuting Pauli operators, stored as a reduced,
+/// independent set of generators.
+///
+/// Internally, generators are kept as rows of the symplectic
+/// `(x | z)` bit matrix and reduced with Gaussian elimination over
+/// GF(2), which makes [`rank`](Self::rank), [`is_independent`](Self::is_independent)
+/// and [`contains`](Self::contains) cheap to compute directly from the
+/// reduced set.
+///
+/// # Example
+///
+/// ```
+/// # use pauli::{DensePauliOperator, StabilizerGroup};
+/// # use pauli::{X, Z};
+/// let generators = vec![
+///     DensePauliOperator::with_paulis(vec![Z, Z, Z, Z]),
+///     DensePauliOperator::with_paulis(vec![X, X, X, X]),
+/// ];
+/// let group = StabilizerGroup::new(&generators);
+///
+/// assert_eq!(group.rank(), 2);
+/// assert!(group.is_independent());
+/// assert!(group.contains(&DensePauliOperator::with_paulis(vec![Z, Z, Z, Z])));
+/// ```
+#[derive(Debug, Clone)]
+pub struct StabilizerGroup {
+    length: usize,
+    original_count: usize,
+    generators: Vec<SymplecticPauli>,
+    pivot_columns: Vec<usize>,
+}
+
+impl StabilizerGroup {
+    /// Builds a stabilizer group from a set of generators, reducing
+    /// them to an independent generating set.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the generators don't all have the same length.
+    pub fn new(generators: &[DensePauliOperator]) -> Self {
+        let length = generators.first().map_or(0, DensePauliOperator::len);
+        assert!(
+            generators.iter().all(|g| g.len() == length),
+            "all generators must have the same length"
+        );
+        let original_count = generators.len();
+        let rows = generators.iter().map(SymplecticPauli::from).collect();
+        let (generators, pivot_columns) = reduce(rows, length);
+        Self {
+            length,
+            original_count,
+            generators,
+            pivot_columns,
+        }
+    }
+
+    /// Returns the number of independent generators.
+    pub fn rank(&self) -> usize {
+        self.generators.len()
+    }
+
+    /// Returns true if none of the original generators were
+    /// redundant, i.e. the reduced set has the same size as the one
+    /// the group was built from.
+    pub fn is_independent(&self) -> bool {
+        self.generators.len() == self.original_count
+    }
+
+    /// Decides if `operator` belongs to the group, see [`Membership`].
+    ///
+    /// # Panic
+    ///
+    /// Panics if `operator` doesn't have the same length as the
+    /// group's generators.
+    pub fn membership(&self, operator: &DensePauliOperator) -> Membership {
+        assert_eq!(
+            operator.len(),
+            self.length,
+            "operator has a different length than the group's generators"
+        );
+        let mut residual = SymplecticPauli::from(operator);
+        for (generator, &column) in self.generators.iter().zip(&self.pivot_columns) {
+            if residual.column(column) {
+                residual = residual.multiply_with(generator).unwrap();
+            }
+        }
+        if !residual.is_identity() {
+            Membership::NotInGroup
+        } else if residual.phase() == Phase::one() {
+            Membership::Member
+        } else {
+            Membership::PhaseMismatch(residual.phase())
+        }
+    }
+
+    /// Checks if `operator` is exactly a product of the group's
+    /// generators, phase included.
+    ///
+    /// # Panic
+    ///
+    /// Panics if `operator` doesn't have the same length as the
+    /// group's generators.
+    pub fn contains(&self, operator: &DensePauliOperator) -> bool {
+        self.membership(operator) == Membership::Member
+    }
+
+    /// Returns the weight distribution of the group: `W[w]` is the
+    /// number of the `2 ** rank()` group elements (including the
+    /// identity) whose operator weight equals `w`.
+    ///
+    /// Enumerates the subsets of generators in Gray code order, so
+    /// consecutive subsets differ by toggling exactly one generator,
+    /// and updates the running weight incrementally instead of
+    /// recomputing it from scratch for each of the `2 ** rank()`
+    /// elements.
+    ///
+    /// # Panic
+    ///
+    /// Panics if the group has 64 or more independent generators,
+    /// since `2 ** rank()` would overflow a `u64` subset counter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use pauli::{DensePauliOperator, StabilizerGroup};
+    /// # use pauli::{X, Z};
+    /// let group = StabilizerGroup::new(&[
+    ///     DensePauliOperator::with_paulis(vec![Z, Z]),
+    ///     DensePauliOperator::with_paulis(vec![X, X]),
+    /// ]);
+    ///
+    /// // I, ZZ, XX and -YY: one weight-0 and three weight-2 elements.
+    /// assert_eq!(group.weight_enumerator(), vec![1, 0, 3]);
+    /// ```
+    pub fn weight_enumerator(&self) -> Vec<usize> {
+        let rank = self.rank();
+        assert!(
+            rank < 64,
+            "weight_enumerator supports at most 63 independent generators, got {}",
+            rank
+        );
+        let mut running = SymplecticPauli::identity(self.length);
+        let mut weight: i64 = 0;
+        let mut distribution = vec![0usize; self.length + 1];
+        distribution[0] = 1;
+        for subset in 1u64..(1u64 << rank) {
+            let toggled = subset.trailing_zeros() as usize;
+            weight += self.generators[toggled].toggle_weight(&mut running);
+            distribution[weight as usize] += 1;
+        }
+        distribution
+    }
+
+    /// Returns a generating set for the centralizer of the group,
+    /// i.e. the operators that commute with every generator.
+    ///
+    /// This is computed as the null space of the symplectic form
+    /// restricted to the generators: the centralizer has dimension
+    /// `2 * n - rank()`, where `n` is the number of qubits.
+    pub fn centralizer_generators(&self) -> Vec<DensePauliOperator> {
+        let swapped_rows = self.generators.iter().map(SymplecticPauli::swapped).collect();
+        let (reduced_rows, pivot_columns) = reduce(swapped_rows, self.length);
+        let total_columns = 2 * self.length;
+        (0..total_columns)
+            .filter(|column| !pivot_columns.contains(column))
+            .map(|free_column| {
+                let mut generator = SymplecticPauli::identity(self.length);
+                generator.set_column(free_column, true);
+                for (row, &pivot_column) in reduced_rows.iter().zip(&pivot_columns) {
+                    generator.set_column(pivot_column, row.column(free_column));
+                }
+                DensePauliOperator::from(&generator)
+            })
+            .collect()
+    }
+}
+
+/// Reduces the rows of a symplectic `(x | z)` bit matrix to row
+/// echelon form with Gaussian elimination over GF(2), clearing each
+/// pivot column from every other row. Returns the non-zero rows
+/// together with the column each one pivots on.
+fn reduce(mut rows: Vec<SymplecticPauli>, length: usize) -> (Vec<SymplecticPauli>, Vec<usize>) {
+    let total_columns = 2 * length;
+    let mut pivot_columns = Vec::new();
+    let mut pivot_row = 0;
+    for column in 0..total_columns {
+        if pivot_row == rows.len() {
+            break;
+        }
+        if let Some(found) = (pivot_row..rows.len()).find(|&row| rows[row].column(column)) {
+            rows.swap(pivot_row, found);
+            for row in 0..rows.len() {
+                if row != pivot_row && rows[row].column(column) {
+                    rows[row] = rows[row].multiply_with(&rows[pivot_row]).unwrap();
+                }
+            }
+            pivot_columns.push(column);
+            pivot_row += 1;
+        }
+    }
+    rows.truncate(pivot_row);
+    (rows, pivot_columns)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{I, X, Z};
+
+    fn steane_generators() -> Vec<DensePauliOperator> {
+        vec![
+            DensePauliOperator::with_paulis(vec![I, I, I, X, X, X, X]),
+            DensePauliOperator::with_paulis(vec![I, X, X, I, I, X, X]),
+            DensePauliOperator::with_paulis(vec![X, I, X, I, X, I, X]),
+            DensePauliOperator::with_paulis(vec![I, I, I, Z, Z, Z, Z]),
+            DensePauliOperator::with_paulis(vec![I, Z, Z, I, I, Z, Z]),
+            DensePauliOperator::with_paulis(vec![Z, I, Z, I, Z, I, Z]),
+        ]
+    }
+
+    #[test]
+    fn rank_of_independent_generators() {
+        let group = StabilizerGroup::new(&steane_generators());
+        assert_eq!(group.rank(), 6);
+        assert!(group.is_independent());
+    }
+
+    #[test]
+    fn redundant_generator_is_dropped() {
+        let mut generators = steane_generators();
+        let duplicate = generators[0].clone();
+        generators.push(duplicate);
+        let group = StabilizerGroup::new(&generators);
+        assert_eq!(group.rank(), 6);
+        assert!(!group.is_independent());
+    }
+
+    #[test]
+    fn contains_products_of_generators() {
+        let generators = steane_generators();
+        let group = StabilizerGroup::new(&generators);
+        let product = &generators[0] * &generators[1];
+        assert!(group.contains(&product));
+        assert!(!group.contains(&DensePauliOperator::with_paulis(vec![X, I, I, I, I, I, I])));
+    }
+
+    #[test]
+    fn phase_mismatch_is_reported() {
+        let generators = vec![DensePauliOperator::with_paulis(vec![Z, Z])];
+        let group = StabilizerGroup::new(&generators);
+        let flipped = Phase::minus_one() * &generators[0];
+        assert_eq!(group.membership(&flipped), Membership::PhaseMismatch(Phase::minus_one()));
+    }
+
+    #[test]
+    fn weight_enumerator_of_two_qubit_group() {
+        let group = StabilizerGroup::new(&[
+            DensePauliOperator::with_paulis(vec![Z, Z]),
+            DensePauliOperator::with_paulis(vec![X, X]),
+        ]);
+        // The group is {I, ZZ, XX, -YY}: one weight-0 and three weight-2 elements.
+        assert_eq!(group.weight_enumerator(), vec![1, 0, 3]);
+    }
+
+    #[test]
+    fn weight_enumerator_matches_brute_force() {
+        let generators = steane_generators();
+        let group = StabilizerGroup::new(&generators);
+
+        let mut brute_force = vec![0usize; generators[0].len() + 1];
+        for subset in 0..(1usize << generators.len()) {
+            let mut element = DensePauliOperator::with_paulis(vec![I; generators[0].len()]);
+            for (bit, generator) in generators.iter().enumerate() {
+                if subset & (1 << bit) != 0 {
+                    element = &element * generator;
+                }
+            }
+            let weight = element.non_trivial_positions().count();
+            brute_force[weight] += 1;
+        }
+
+        assert_eq!(group.weight_enumerator(), brute_force);
+    }
+
+    #[test]
+    fn centralizer_commutes_with_every_generator() {
+        let generators = steane_generators();
+        let group = StabilizerGroup::new(&generators);
+        let centralizer = group.centralizer_generators();
+        assert_eq!(centralizer.len(), 2 * 7 - 6);
+        for operator in &centralizer {
+            for generator in &generators {
+                assert!(operator.commutes_with(generator));
+            }
+        }
+    }
+}